@@ -4,4 +4,13 @@ use thiserror::Error;
 pub enum AirmailError {
     #[error("unable to count")]
     UnableToCount,
+
+    #[error("remote index url is empty")]
+    EmptyRemoteUrl,
+
+    #[error("unsupported scheme `{scheme}` in remote index url `{url}`")]
+    UnsupportedRemoteScheme { url: String, scheme: String },
+
+    #[error("unable to parse remote index url `{url}`: {reason}")]
+    InvalidRemoteUrl { url: String, reason: String },
 }
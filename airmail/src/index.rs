@@ -135,7 +135,27 @@ impl AirmailIndex {
         })
     }
 
+    /// Checks that `base_url` looks like something the remote directory can fetch from, so that
+    /// misconfiguration is reported at startup instead of on the first read.
+    fn validate_remote_url(base_url: &str) -> Result<(), AirmailError> {
+        if base_url.trim().is_empty() {
+            return Err(AirmailError::EmptyRemoteUrl);
+        }
+        let url = reqwest::Url::parse(base_url).map_err(|e| AirmailError::InvalidRemoteUrl {
+            url: base_url.to_string(),
+            reason: e.to_string(),
+        })?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(AirmailError::UnsupportedRemoteScheme {
+                url: base_url.to_string(),
+                scheme: url.scheme().to_string(),
+            });
+        }
+        Ok(())
+    }
+
     pub fn new_remote(base_url: &str) -> Result<Self> {
+        Self::validate_remote_url(base_url)?;
         let tantivy_index =
             tantivy::Index::open(RemoteDirectory::<{ 2 * 1024 * 1024 }>::new(base_url))?;
         Ok(Self {
@@ -472,3 +492,25 @@ impl AirmailIndexWriter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{error::AirmailError, index::AirmailIndex};
+
+    #[test]
+    fn test_validate_remote_url() {
+        assert!(AirmailIndex::validate_remote_url("https://example.com/index").is_ok());
+        assert!(matches!(
+            AirmailIndex::validate_remote_url(""),
+            Err(AirmailError::EmptyRemoteUrl)
+        ));
+        assert!(matches!(
+            AirmailIndex::validate_remote_url("ftp://example.com/index"),
+            Err(AirmailError::UnsupportedRemoteScheme { .. })
+        ));
+        assert!(matches!(
+            AirmailIndex::validate_remote_url("http://[::1"),
+            Err(AirmailError::InvalidRemoteUrl { .. })
+        ));
+    }
+}